@@ -1,10 +1,10 @@
 use ratatui::buffer::Buffer;
-use ratatui::layout::Rect;
+use ratatui::layout::{Position, Rect};
 use ratatui::widgets::WidgetRef;
 use std::error::Error;
 use std::thread;
 use std::time::{Duration, Instant};
-use tui_event_controller::EventfulWidget;
+use tui_event_controller::{Broadcast, EventfulWidget, Locatable, Propagation};
 
 type Result<T> = std::result::Result<T, Box<dyn Error>>;
 
@@ -17,6 +17,18 @@ enum AppEvent {
     Tick,
 }
 
+impl Locatable for AppEvent {
+    fn position(&self) -> Option<Position> {
+        None
+    }
+}
+
+impl Broadcast for AppEvent {
+    fn is_broadcast(&self) -> bool {
+        matches!(self, AppEvent::Tick)
+    }
+}
+
 #[derive(Default)]
 struct AppState {
     counter: usize,
@@ -45,7 +57,7 @@ impl EventfulWidget<AppState, AppEvent> for App {
         String::from("App")
     }
 
-    fn on_event(ctx: EventContext, state: &mut AppState, _: Option<Rect>) {
+    fn on_event(ctx: EventContext, state: &mut AppState, _: Option<Rect>) -> Propagation {
         match ctx.event {
             AppEvent::Tick => {
                 println!("App: tick");
@@ -54,6 +66,7 @@ impl EventfulWidget<AppState, AppEvent> for App {
                 }
             }
         }
+        Propagation::Ignore
     }
 }
 
@@ -73,13 +86,14 @@ impl EventfulWidget<AppState, AppEvent> for HomePage {
         String::from("HomePage")
     }
 
-    fn on_event(ctx: EventContext, state: &mut AppState, _: Option<Rect>) {
+    fn on_event(ctx: EventContext, state: &mut AppState, _: Option<Rect>) -> Propagation {
         match ctx.event {
             AppEvent::Tick => {
                 state.counter += 1;
                 println!("HomePage: tick {:}", state.counter);
             }
         }
+        Propagation::Ignore
     }
 }
 
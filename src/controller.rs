@@ -1,7 +1,9 @@
+use ratatui::layout::{Position, Rect};
 use std::cell::RefCell;
 use std::collections::HashMap;
 use std::rc::Rc;
 use std::sync::mpsc;
+use std::time::{Duration, Instant};
 
 /// Manages events and their associated callbacks in a Ratatui application.
 ///
@@ -23,18 +25,26 @@ impl<S, E> EventController<S, E> {
         }
     }
 
-    /// Registers an event listener under the specified `id`.
+    /// Registers an event listener under the specified `id` with the given
+    /// `priority`.
     ///
     /// The `id` must be unique, as there can only be one callback associated
     /// with a given `id`. If a callback with the same `id` already exists,
     /// it will be replaced.
     ///
+    /// Listeners are notified in descending `priority` order (ties broken by
+    /// registration order), and a listener can return [`Propagation::Consume`]
+    /// to stop the event from reaching lower-priority listeners — see
+    /// [`Self::recv_and_notify`].
+    ///
     /// # Note
     /// Ensure to remove the callback when it is no longer needed using
     /// [`Self::remove_listener`].
     ///
     /// # Example
     /// ```
+    /// use tui_event_controller::Propagation;
+    ///
     /// type EventController = tui_event_controller::EventController<AppState, AppEvent>;
     ///
     /// #[derive(Debug)]
@@ -44,15 +54,16 @@ impl<S, E> EventController<S, E> {
     /// struct AppEvent;
     ///
     /// let controller = EventController::new();
-    /// controller.add_listener("foo", move |ctx, _state| {
+    /// controller.add_listener("foo", 0, move |ctx, _state| {
     ///     println!("received: {:?}", ctx.event);
+    ///     Propagation::Ignore
     /// });
     /// ```
-    pub fn add_listener<F>(&self, id: &str, callback: F)
+    pub fn add_listener<F>(&self, id: &str, priority: i32, callback: F)
     where
-        F: Fn(EventContext<S, E>, &mut S) + 'static,
+        F: Fn(EventContext<S, E>, &mut S) -> Propagation + 'static,
     {
-        self.rc.borrow_mut().add_listener(id, callback);
+        self.rc.borrow_mut().add_listener(id, priority, callback);
     }
 
     /// Removes a listener with a given `id`.
@@ -82,12 +93,85 @@ impl<S, E> EventController<S, E> {
         self.rc.borrow().sender.clone()
     }
 
+    /// Emits a [`LifecycleEvent::Resize`] with the new terminal size.
+    ///
+    /// Call this from wherever the app detects a terminal resize; it is
+    /// delivered to every listener's [`EventfulWidget::on_lifecycle`] hook
+    /// the next time an `*_and_notify` method runs.
+    pub fn emit_resize(&self, width: u16, height: u16) {
+        let _ = self
+            .rc
+            .borrow()
+            .lifecycle_sender
+            .send(LifecycleEvent::Resize { width, height });
+    }
+
+    /// Registers a lifecycle hook under `id`, notified independently of the
+    /// user's own event enum `E`. Used internally by
+    /// [`InteractiveWidget`](crate::InteractiveWidget) to wire up
+    /// [`EventfulWidget::on_lifecycle`].
+    pub(super) fn add_lifecycle_listener<F>(&self, id: &str, callback: F)
+    where
+        F: Fn(LifecycleContext<S, E>, &mut S, LifecycleEvent) + 'static,
+    {
+        self.rc
+            .borrow_mut()
+            .lifecycle_callbacks
+            .insert(id.to_string(), Rc::new(callback));
+    }
+
+    /// Dispatches every currently queued [`LifecycleEvent`] to all
+    /// registered lifecycle hooks.
+    fn drain_lifecycle(&self, state: &mut S) {
+        loop {
+            let event = self.rc.borrow().lifecycle_receiver.try_recv().ok();
+            let Some(event) = event else {
+                return;
+            };
+
+            let callbacks = self.rc.borrow().lifecycle_callbacks.clone();
+            for callback in callbacks.values() {
+                let ctx = LifecycleContext { controller: self };
+                (callback)(ctx, state, event.clone());
+            }
+        }
+    }
+
     /// Waits for an events and send the event to all listeners.
     ///
     /// This function will block the current thread until an event
     /// is received. Once the message is receveived, all listeners
     /// that registered with [`Self::add_listener`] are notified.
     ///
+    /// If a listener currently holds exclusive capture (see
+    /// [`Self::push_exclusive`]), an event that is not [`Broadcast`] is
+    /// routed only to that listener; a [`Broadcast`] event still reaches
+    /// every listener.
+    ///
+    /// Otherwise, listeners are notified in descending priority order (see
+    /// [`Self::add_listener`]) and a listener returning
+    /// [`Propagation::Consume`] stops the event from reaching
+    /// lower-priority listeners — unless `event` is [`Broadcast`], in which
+    /// case every listener is notified regardless of consumption. This lets
+    /// a focused modal swallow a key without also swallowing cadence events
+    /// like a `Tick` that everyone still needs to see.
+    ///
+    /// If `event` implements [`Locatable`] and yields a position, it is
+    /// additionally hit-tested against the render areas tracked for each
+    /// listener (see [`InteractiveWidget`](crate::InteractiveWidget)): a
+    /// [`LifecycleEvent::HoverChanged`] is emitted whenever the cursor
+    /// enters or leaves a tracked area, and a button-down landing inside one
+    /// arms a press-and-hold timer (see [`Self::set_hold_duration`]) that
+    /// re-notifies the listener if no matching release follows in time.
+    ///
+    /// See [`Self::try_recv_and_notify`], [`Self::drain_and_notify`] and
+    /// [`Self::recv_timeout_and_notify`] for non-blocking alternatives.
+    ///
+    /// Any queued [`LifecycleEvent`]s (init, resize, focus-changed,
+    /// hover-changed) are interleaved ahead of the next regular event and
+    /// delivered to every listener's
+    /// [`EventfulWidget::on_lifecycle`](crate::EventfulWidget::on_lifecycle) hook.
+    ///
     /// # Errors
     ///
     /// Returns an [`mpsc::RecvError`] if the channel has hang up.
@@ -104,16 +188,328 @@ impl<S, E> EventController<S, E> {
     ///
     /// controller.recv_and_notify(&mut state)?;
     /// ```
-    pub fn recv_and_notify(&self, state: &mut S) -> Result<(), mpsc::RecvError> {
-        let event = self.rc.borrow().receiver.recv()?;
+    pub fn recv_and_notify(&self, state: &mut S) -> Result<(), mpsc::RecvError>
+    where
+        E: Locatable + Broadcast + Clone,
+    {
+        let poll_interval = self.rc.borrow().hold_duration;
+        loop {
+            match self.recv_timeout_and_notify(state, poll_interval) {
+                Ok(true) => return Ok(()),
+                Ok(false) => continue,
+                Err(mpsc::RecvTimeoutError::Disconnected) => return Err(mpsc::RecvError),
+                Err(mpsc::RecvTimeoutError::Timeout) => unreachable!(
+                    "recv_timeout_and_notify reports a timeout as Ok(false), not an error"
+                ),
+            }
+        }
+    }
 
-        let callbacks = self.rc.borrow().callbacks.clone();
-        for callback in callbacks.values() {
-            let ctx = EventContext::new(self, &event);
-            (callback)(ctx, state);
+    /// Tries to receive and dispatch a single event without blocking.
+    ///
+    /// Returns `Ok(true)` if an event was processed, `Ok(false)` if the
+    /// channel was empty. Useful for draining a burst of queued events (a
+    /// resize followed by several keys and a tick) without the render loop
+    /// stalling on one event per frame.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`mpsc::TryRecvError::Disconnected`] if the channel has
+    /// hung up.
+    pub fn try_recv_and_notify(&self, state: &mut S) -> Result<bool, mpsc::TryRecvError>
+    where
+        E: Locatable + Broadcast + Clone,
+    {
+        self.drain_lifecycle(state);
+
+        // Bind the result before matching on it: matching directly on
+        // `self.rc.borrow().receiver.try_recv()` would keep the `Ref` guard
+        // alive for the whole match body, and `notify`/`route_mouse` below
+        // need their own `borrow_mut()`.
+        let result = self.rc.borrow().receiver.try_recv();
+        match result {
+            Ok(event) => {
+                self.notify(&event, state);
+                self.route_mouse(&event, state);
+                Ok(true)
+            }
+            Err(mpsc::TryRecvError::Empty) => {
+                self.notify_held(state);
+                Ok(false)
+            }
+            Err(err @ mpsc::TryRecvError::Disconnected) => Err(err),
+        }
+    }
+
+    /// Processes every event currently queued on the channel, without
+    /// blocking for more once it is empty.
+    ///
+    /// Lets the render loop fully catch up on a burst of events and redraw
+    /// once, instead of redrawing after every single event.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`mpsc::TryRecvError::Disconnected`] if the channel has
+    /// hung up.
+    pub fn drain_and_notify(&self, state: &mut S) -> Result<usize, mpsc::TryRecvError>
+    where
+        E: Locatable + Broadcast + Clone,
+    {
+        let mut processed = 0;
+        while self.try_recv_and_notify(state)? {
+            processed += 1;
+        }
+        Ok(processed)
+    }
+
+    /// Waits up to `timeout` for an event and dispatches it if one arrives.
+    ///
+    /// Returns `Ok(true)` if an event was processed, `Ok(false)` if
+    /// `timeout` elapsed first. Intended for frame-paced render loops that
+    /// need to redraw on a cadence even without new events.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`mpsc::RecvTimeoutError::Disconnected`] if the channel
+    /// has hung up.
+    pub fn recv_timeout_and_notify(
+        &self,
+        state: &mut S,
+        timeout: Duration,
+    ) -> Result<bool, mpsc::RecvTimeoutError>
+    where
+        E: Locatable + Broadcast + Clone,
+    {
+        self.drain_lifecycle(state);
+
+        // See `try_recv_and_notify`: bind the result before matching so the
+        // `Ref` guard doesn't outlive the borrow and collide with the
+        // `borrow_mut()` calls inside `notify`/`route_mouse`.
+        let result = self.rc.borrow().receiver.recv_timeout(timeout);
+        match result {
+            Ok(event) => {
+                self.notify(&event, state);
+                self.route_mouse(&event, state);
+                Ok(true)
+            }
+            Err(mpsc::RecvTimeoutError::Timeout) => {
+                self.notify_held(state);
+                Ok(false)
+            }
+            Err(err @ mpsc::RecvTimeoutError::Disconnected) => Err(err),
+        }
+    }
+
+    /// Dispatches `event` to listeners, honoring exclusive capture and
+    /// priority-ordered consumption.
+    fn notify(&self, event: &E, state: &mut S)
+    where
+        E: Broadcast,
+    {
+        let broadcast = event.is_broadcast();
+        let exclusive = self.rc.borrow().focus_stack.last().cloned();
+
+        if let Some(id) = exclusive {
+            if !broadcast {
+                // An exclusive listener is on top of the focus stack: only
+                // it gets to see the event.
+                let callback = self.rc.borrow().callbacks.get(&id).map(|e| e.callback.clone());
+                if let Some(callback) = callback {
+                    let ctx = EventContext::new(self, event);
+                    (callback)(ctx, state);
+                }
+                return;
+            }
+            // `event` is `Broadcast`: it must still reach everyone, so fall
+            // through to the regular priority-ordered dispatch below
+            // instead of restricting delivery to the exclusive listener.
+        }
+
+        let mut entries: Vec<_> = self.rc.borrow().callbacks.values().cloned().collect();
+        entries.sort_by(|a, b| b.priority.cmp(&a.priority).then(a.seq.cmp(&b.seq)));
+
+        for entry in entries {
+            let ctx = EventContext::new(self, event);
+            let propagation = (entry.callback)(ctx, state);
+            if !broadcast && propagation == Propagation::Consume {
+                break;
+            }
+        }
+    }
+
+    /// Hit-tests `event` against tracked widget areas, emitting a
+    /// [`LifecycleEvent::HoverChanged`] when the cursor enters or leaves one,
+    /// and arming/disarming the press-and-hold timer.
+    ///
+    /// Hover transitions go through the lifecycle channel rather than
+    /// replaying `event` through [`EventfulWidget::on_event`](crate::EventfulWidget::on_event):
+    /// that callback is already invoked once per event by [`Self::notify`],
+    /// so calling it a second time here would deliver the same event twice.
+    ///
+    /// Honors exclusive capture the same way [`Self::notify`] does: while a
+    /// listener is on top of the focus stack, only its own area can be hit,
+    /// so a modal's background can't hover or press-hold a widget it's
+    /// covering.
+    fn route_mouse(&self, event: &E, _state: &mut S)
+    where
+        E: Locatable + Clone,
+    {
+        let Some(pos) = event.position() else {
+            return;
+        };
+
+        let exclusive = self.rc.borrow().focus_stack.last().cloned();
+
+        let hit = {
+            let inner = self.rc.borrow();
+            let mut candidates: Vec<_> = inner
+                .areas
+                .iter()
+                .filter(|(_, area)| area.contains(pos))
+                .filter_map(|(id, _)| {
+                    inner
+                        .callbacks
+                        .get(id)
+                        .map(|entry| (id.clone(), entry.priority, entry.seq))
+                })
+                .collect();
+            // Top-most (highest priority, then earliest registered)
+            // area wins when several overlap, matching `notify`'s order.
+            candidates.sort_by(|a, b| b.1.cmp(&a.1).then(a.2.cmp(&b.2)));
+            candidates.into_iter().next().map(|(id, ..)| id)
+        };
+        let hit = match &exclusive {
+            Some(id) if hit.as_deref() != Some(id.as_str()) => None,
+            _ => hit,
+        };
+
+        let previous = self.rc.borrow().hovered.clone();
+        if previous != hit {
+            let _ = self
+                .rc
+                .borrow()
+                .lifecycle_sender
+                .send(LifecycleEvent::HoverChanged { id: hit.clone() });
+            self.rc.borrow_mut().hovered = hit.clone();
+        }
+
+        if event.is_press() {
+            if let Some(id) = hit {
+                let deadline = Instant::now() + self.rc.borrow().hold_duration;
+                self.rc.borrow_mut().press_hold = Some((id, event.clone(), deadline));
+            }
+        } else if event.is_release() {
+            self.rc.borrow_mut().press_hold = None;
+        }
+    }
+
+    /// Fires a synthetic "held" notification if a press has outlived
+    /// [`Self::set_hold_duration`] without a matching release.
+    fn notify_held(&self, state: &mut S)
+    where
+        E: Clone,
+    {
+        let held = {
+            let inner = self.rc.borrow();
+            inner
+                .press_hold
+                .as_ref()
+                .filter(|(_, _, deadline)| Instant::now() >= *deadline)
+                .map(|(id, event, _)| (id.clone(), event.clone()))
+        };
+
+        let Some((id, event)) = held else {
+            return;
+        };
+
+        let exclusive = self.rc.borrow().focus_stack.last().cloned();
+        if exclusive.is_some_and(|excl| excl != id) {
+            // A modal grabbed capture after this press was armed; the
+            // background listener it belongs to must not fire.
+            self.rc.borrow_mut().press_hold = None;
+            return;
+        }
+
+        let callback = self.rc.borrow().callbacks.get(&id).map(|e| e.callback.clone());
+        if let Some(callback) = callback {
+            (callback)(EventContext::new(self, &event), state);
+        }
+        // Don't re-fire every poll interval; a fresh press re-arms it.
+        self.rc.borrow_mut().press_hold = None;
+    }
+
+    /// Sets how long a mouse button must stay pressed inside a listener's
+    /// area before a synthetic "held" notification is dispatched to it.
+    ///
+    /// Defaults to 500ms.
+    pub fn set_hold_duration(&self, duration: Duration) {
+        self.rc.borrow_mut().hold_duration = duration;
+    }
+
+    /// Updates the last known render area for the listener registered under
+    /// `id`.
+    ///
+    /// Called automatically by
+    /// [`InteractiveWidget`](crate::InteractiveWidget) and
+    /// [`InteractiveStatefulWidget`](crate::InteractiveStatefulWidget) on
+    /// every render; mouse routing in [`Self::recv_and_notify`] hit-tests
+    /// against whatever was recorded here most recently.
+    pub(super) fn update_area(&self, id: &str, area: Rect) {
+        self.rc.borrow_mut().areas.insert(id.to_string(), area);
+    }
+
+    /// Grabs exclusive input capture for the listener registered under `id`.
+    ///
+    /// While `id` is on top of the focus stack, [`Self::recv_and_notify`]
+    /// dispatches events that are not [`Broadcast`] only to its callback, so
+    /// background listeners stop reacting to input meant for a modal or popup. Capture is released
+    /// either by dropping the returned guard or by calling
+    /// [`Self::pop_exclusive`] explicitly.
+    ///
+    /// Grabbing or releasing exclusive capture also emits a
+    /// [`LifecycleEvent::FocusChanged`] carrying the id now on top of the
+    /// focus stack, so widgets can react to focus gained/lost via
+    /// [`EventfulWidget::on_lifecycle`](crate::EventfulWidget::on_lifecycle)
+    /// without smuggling it into their own event enum.
+    ///
+    /// # Example
+    /// ```ignore
+    /// type EventController = tui_event_controller::EventController<AppState, AppEvent>;
+    ///
+    /// struct AppState;
+    /// struct AppEvent;
+    ///
+    /// let controller = EventController::new();
+    /// let _guard = controller.push_exclusive("modal");
+    /// ```
+    #[must_use]
+    pub fn push_exclusive(&self, id: &str) -> ExclusiveGuard<S, E> {
+        self.rc.borrow_mut().focus_stack.push(id.to_string());
+        let _ = self
+            .rc
+            .borrow()
+            .lifecycle_sender
+            .send(LifecycleEvent::FocusChanged {
+                id: Some(id.to_string()),
+            });
+        ExclusiveGuard {
+            controller: self.rc_clone(),
+            id: id.to_string(),
         }
+    }
 
-        Ok(())
+    /// Releases exclusive input capture held for `id`.
+    ///
+    /// It is safe to call this even if `id` is not currently on the focus
+    /// stack, or is no longer on top of it.
+    pub fn pop_exclusive(&self, id: &str) {
+        self.rc.borrow_mut().focus_stack.retain(|key| key != id);
+        let current = self.rc.borrow().focus_stack.last().cloned();
+        let _ = self
+            .rc
+            .borrow()
+            .lifecycle_sender
+            .send(LifecycleEvent::FocusChanged { id: current });
     }
 
     /// Returns an [`Rc::clone`]d instance of the `EventController`.
@@ -145,7 +541,106 @@ impl<'a, S, E> EventContext<'a, S, E> {
     }
 }
 
-type EventCallback<S, E> = Rc<dyn Fn(EventContext<S, E>, &mut S) + 'static>;
+/// Lifecycle events delivered independently of the application's own event
+/// enum `E`, so apps don't have to smuggle things like a terminal resize
+/// into their own event type.
+///
+/// Handled via [`EventfulWidget::on_lifecycle`](crate::EventfulWidget::on_lifecycle).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LifecycleEvent {
+    /// Fired once, the first time any widget registers with the controller.
+    Init,
+    /// The terminal was resized; see [`EventController::emit_resize`].
+    Resize { width: u16, height: u16 },
+    /// The id on top of the focus stack changed; see
+    /// [`EventController::push_exclusive`]/[`EventController::pop_exclusive`].
+    /// `None` means no listener currently holds exclusive capture.
+    FocusChanged { id: Option<String> },
+    /// The id of the listener whose tracked area currently owns the cursor
+    /// changed; see mouse routing in [`EventController::recv_and_notify`].
+    /// `None` means the cursor isn't over any tracked area.
+    HoverChanged { id: Option<String> },
+}
+
+/// Provides controller access to a
+/// [`EventfulWidget::on_lifecycle`](crate::EventfulWidget::on_lifecycle) hook.
+pub struct LifecycleContext<'a, S, E> {
+    pub controller: &'a EventController<S, E>,
+}
+
+/// Exposes the screen position (and press/release semantics) of an event,
+/// so mouse routing in [`EventController::recv_and_notify`] can hit-test it
+/// against tracked widget areas without knowing anything about the concrete
+/// event enum `E`.
+///
+/// Implement this on your application's event enum to opt into hover and
+/// press-and-hold routing.
+pub trait Locatable {
+    /// The cursor position associated with the event, or `None` if the
+    /// event has no spatial component (e.g. a tick or a key press).
+    fn position(&self) -> Option<Position>;
+
+    /// Whether this event is a mouse button being pressed down.
+    ///
+    /// Used to arm the press-and-hold timer. Defaults to `false`.
+    fn is_press(&self) -> bool {
+        false
+    }
+
+    /// Whether this event is a mouse button being released.
+    ///
+    /// Used to disarm the press-and-hold timer. Defaults to `false`.
+    fn is_release(&self) -> bool {
+        false
+    }
+}
+
+/// Whether a listener consumed an event or let it propagate to the next
+/// listener in priority order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Propagation {
+    /// The event was handled; stop notifying lower-priority listeners.
+    Consume,
+    /// The event was ignored; continue notifying lower-priority listeners.
+    Ignore,
+}
+
+/// Lets an event opt out of priority-ordered consumption, so it always
+/// reaches every listener regardless of whether an earlier one consumed it.
+///
+/// Useful for cadence events like a `Tick` that every widget needs to see,
+/// as opposed to input events like a `Key` where a focused modal should be
+/// able to swallow them. Implement this on your application's event enum;
+/// it defaults to `false` for every event.
+pub trait Broadcast {
+    /// Whether this event should always reach every listener, bypassing
+    /// consumption. Defaults to `false`.
+    fn is_broadcast(&self) -> bool {
+        false
+    }
+}
+
+type EventCallback<S, E> = Rc<dyn Fn(EventContext<S, E>, &mut S) -> Propagation + 'static>;
+
+type LifecycleCallback<S, E> = Rc<dyn Fn(LifecycleContext<S, E>, &mut S, LifecycleEvent) + 'static>;
+
+/// A registered listener: its callback, dispatch priority, and registration
+/// sequence (used to break priority ties in a stable order).
+struct ListenerEntry<S, E> {
+    priority: i32,
+    seq: u64,
+    callback: EventCallback<S, E>,
+}
+
+impl<S, E> Clone for ListenerEntry<S, E> {
+    fn clone(&self) -> Self {
+        Self {
+            priority: self.priority,
+            seq: self.seq,
+            callback: Rc::clone(&self.callback),
+        }
+    }
+}
 
 /// The internal implementation of `EventController`.
 struct InternalEventController<S, E> {
@@ -156,7 +651,40 @@ struct InternalEventController<S, E> {
     receiver: mpsc::Receiver<E>,
 
     /// Registered callbacks.
-    callbacks: HashMap<String, EventCallback<S, E>>,
+    callbacks: HashMap<String, ListenerEntry<S, E>>,
+
+    /// Monotonically increasing counter handed out to new listeners, so
+    /// same-priority listeners are still notified in registration order.
+    next_seq: u64,
+
+    /// Lifecycle event sender channel, independent of `E`.
+    lifecycle_sender: mpsc::Sender<LifecycleEvent>,
+
+    /// Lifecycle event receiver channel.
+    lifecycle_receiver: mpsc::Receiver<LifecycleEvent>,
+
+    /// Registered [`EventfulWidget::on_lifecycle`](crate::EventfulWidget::on_lifecycle) hooks.
+    lifecycle_callbacks: HashMap<String, LifecycleCallback<S, E>>,
+
+    /// Whether [`LifecycleEvent::Init`] has already been fired.
+    initialized: bool,
+
+    /// Ids of listeners currently holding exclusive input capture, in the
+    /// order they grabbed it. The last entry wins.
+    focus_stack: Vec<String>,
+
+    /// Last known render area per listener id, used for mouse hit-testing.
+    areas: HashMap<String, Rect>,
+
+    /// Id of the listener whose area currently owns the cursor, if any.
+    hovered: Option<String>,
+
+    /// The listener id, originating press event, and deadline for an
+    /// in-flight press-and-hold, if any.
+    press_hold: Option<(String, E, Instant)>,
+
+    /// How long a press must go unreleased before it counts as "held".
+    hold_duration: Duration,
 }
 
 impl<S, E> InternalEventController<S, E> {
@@ -164,25 +692,210 @@ impl<S, E> InternalEventController<S, E> {
     #[must_use]
     fn new() -> Self {
         let (sender, receiver) = mpsc::channel();
+        let (lifecycle_sender, lifecycle_receiver) = mpsc::channel();
         let callbacks = HashMap::default();
 
         Self {
             sender,
             receiver,
             callbacks,
+            next_seq: 0,
+            lifecycle_sender,
+            lifecycle_receiver,
+            lifecycle_callbacks: HashMap::default(),
+            initialized: false,
+            focus_stack: Vec::new(),
+            areas: HashMap::new(),
+            hovered: None,
+            press_hold: None,
+            hold_duration: Duration::from_millis(500),
         }
     }
 
     /// Adds a new listener.
-    fn add_listener<F>(&mut self, id: &str, callback: F)
+    fn add_listener<F>(&mut self, id: &str, priority: i32, callback: F)
     where
-        F: Fn(EventContext<S, E>, &mut S) + 'static,
+        F: Fn(EventContext<S, E>, &mut S) -> Propagation + 'static,
     {
-        self.callbacks.insert(id.to_string(), Rc::new(callback));
+        if !self.initialized {
+            self.initialized = true;
+            let _ = self.lifecycle_sender.send(LifecycleEvent::Init);
+        }
+
+        let seq = self.next_seq;
+        self.next_seq += 1;
+        self.callbacks.insert(
+            id.to_string(),
+            ListenerEntry {
+                priority,
+                seq,
+                callback: Rc::new(callback),
+            },
+        );
     }
 
     /// Removes a listener.
     fn remove_listener(&mut self, id: &str) {
         let _ = self.callbacks.remove(id);
+        let _ = self.lifecycle_callbacks.remove(id);
+        // A removed listener can no longer receive anything, so it must not
+        // be left behind on the focus stack; otherwise a vanished modal
+        // would permanently swallow all input.
+        let was_top = self.focus_stack.last().is_some_and(|top| top == id);
+        self.focus_stack.retain(|key| key != id);
+        if was_top {
+            // Dropping a widget that held exclusive capture (e.g. its
+            // `ExclusiveGuard` outlived it) still hands focus back, the
+            // same way `EventController::pop_exclusive` does.
+            let current = self.focus_stack.last().cloned();
+            let _ = self
+                .lifecycle_sender
+                .send(LifecycleEvent::FocusChanged { id: current });
+        }
+    }
+}
+
+/// RAII guard returned by [`EventController::push_exclusive`].
+///
+/// Releases exclusive input capture when dropped, restoring delivery to
+/// whatever was below it on the focus stack (or broadcasting again, if the
+/// stack is now empty).
+pub struct ExclusiveGuard<S, E> {
+    controller: EventController<S, E>,
+    id: String,
+}
+
+impl<S, E> Drop for ExclusiveGuard<S, E> {
+    fn drop(&mut self) {
+        self.controller.pop_exclusive(&self.id);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    enum TestEvent {
+        Tick,
+        MouseMove { x: u16, y: u16 },
+    }
+
+    impl Locatable for TestEvent {
+        fn position(&self) -> Option<Position> {
+            match self {
+                Self::MouseMove { x, y } => Some(Position::new(*x, *y)),
+                Self::Tick => None,
+            }
+        }
+    }
+
+    impl Broadcast for TestEvent {
+        fn is_broadcast(&self) -> bool {
+            matches!(self, Self::Tick)
+        }
+    }
+
+    #[derive(Default)]
+    struct TestState {
+        background_hits: usize,
+        modal_hits: usize,
+    }
+
+    #[test]
+    fn broadcast_event_reaches_every_listener_despite_exclusive_capture() {
+        let controller: EventController<TestState, TestEvent> = EventController::new();
+        controller.add_listener("background", 0, |_ctx, state: &mut TestState| {
+            state.background_hits += 1;
+            Propagation::Ignore
+        });
+        controller.add_listener("modal", 0, |_ctx, state: &mut TestState| {
+            state.modal_hits += 1;
+            Propagation::Ignore
+        });
+        let _guard = controller.push_exclusive("modal");
+
+        let mut state = TestState::default();
+        controller.get_sender().send(TestEvent::Tick).unwrap();
+        controller.recv_and_notify(&mut state).unwrap();
+
+        assert_eq!(
+            state.background_hits, 1,
+            "a Broadcast event must still reach background listeners while a modal holds capture"
+        );
+        assert_eq!(state.modal_hits, 1);
+    }
+
+    #[test]
+    fn non_broadcast_event_is_restricted_to_the_exclusive_listener() {
+        let controller: EventController<TestState, TestEvent> = EventController::new();
+        controller.add_listener("background", 0, |_ctx, state: &mut TestState| {
+            state.background_hits += 1;
+            Propagation::Ignore
+        });
+        controller.add_listener("modal", 0, |_ctx, state: &mut TestState| {
+            state.modal_hits += 1;
+            Propagation::Ignore
+        });
+        let _guard = controller.push_exclusive("modal");
+
+        let mut state = TestState::default();
+        controller
+            .get_sender()
+            .send(TestEvent::MouseMove { x: 0, y: 0 })
+            .unwrap();
+        controller.recv_and_notify(&mut state).unwrap();
+
+        assert_eq!(state.background_hits, 0);
+        assert_eq!(state.modal_hits, 1);
+    }
+
+    #[test]
+    fn hit_test_prefers_the_highest_priority_overlapping_area() {
+        let controller: EventController<TestState, TestEvent> = EventController::new();
+        let area = Rect::new(0, 0, 10, 10);
+        controller.add_listener("low", 0, |_ctx, _state: &mut TestState| Propagation::Ignore);
+        controller.add_listener("high", 10, |_ctx, _state: &mut TestState| Propagation::Ignore);
+        controller.update_area("low", area);
+        controller.update_area("high", area);
+
+        let mut state = TestState::default();
+        controller
+            .get_sender()
+            .send(TestEvent::MouseMove { x: 1, y: 1 })
+            .unwrap();
+        controller.recv_and_notify(&mut state).unwrap();
+
+        assert_eq!(
+            controller.rc.borrow().hovered.as_deref(),
+            Some("high"),
+            "the higher-priority area should win a hit-test tie"
+        );
+    }
+
+    #[test]
+    fn mouse_move_into_a_tracked_area_dispatches_on_event_exactly_once() {
+        let controller: EventController<TestState, TestEvent> = EventController::new();
+        controller.add_listener("widget", 0, |_ctx, state: &mut TestState| {
+            state.background_hits += 1;
+            Propagation::Ignore
+        });
+        controller.update_area("widget", Rect::new(0, 0, 10, 10));
+
+        let mut state = TestState::default();
+        controller
+            .get_sender()
+            .send(TestEvent::MouseMove { x: 1, y: 1 })
+            .unwrap();
+        // This also exercises the `RefCell` borrow in `recv_and_notify`'s
+        // underlying `try_recv`/`recv_timeout` match: it used to panic with
+        // "already borrowed" the moment a positioned event landed inside a
+        // tracked area.
+        controller.recv_and_notify(&mut state).unwrap();
+
+        assert_eq!(
+            state.background_hits, 1,
+            "hover routing must not replay the event through on_event a second time"
+        );
     }
 }
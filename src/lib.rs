@@ -5,7 +5,7 @@
 //! # Example
 //!
 //! ```no_run
-//! use tui_event_controller::EventfulWidget;
+//! use tui_event_controller::{EventfulWidget, Propagation};
 //! use ratatui::prelude::*;
 //! use ratatui::widgets::WidgetRef;
 //! use ratatui::crossterm::event::{self, KeyEvent, Event as CrosstermEvent};
@@ -15,11 +15,24 @@
 //! type EventController = tui_event_controller::EventController<AppState, AppEvent>;
 //! type IWidget<W> = tui_event_controller::InteractiveWidget<AppState, AppEvent, W>;
 //!
+//! #[derive(Clone)]
 //! enum AppEvent {
 //!     Tick,
 //!     Key(KeyEvent),
 //! }
 //!
+//! impl tui_event_controller::Locatable for AppEvent {
+//!     fn position(&self) -> Option<Position> {
+//!         None
+//!     }
+//! }
+//!
+//! impl tui_event_controller::Broadcast for AppEvent {
+//!     fn is_broadcast(&self) -> bool {
+//!         matches!(self, AppEvent::Tick)
+//!     }
+//! }
+//!
 //! #[derive(Default)]
 //! struct AppState {
 //!     counter: usize,
@@ -38,7 +51,7 @@
 //!         String::from("App")
 //!     }
 //!
-//!     fn on_event(ctx: EventContext, state: &mut AppState, _: Option<Rect>) {
+//!     fn on_event(ctx: EventContext, state: &mut AppState, _: Option<Rect>) -> Propagation {
 //!         match ctx.event {
 //!             AppEvent::Tick => {
 //!                 state.counter += 1;
@@ -46,6 +59,7 @@
 //!             }
 //!             AppEvent::Key(_) => {}
 //!         }
+//!         Propagation::Ignore
 //!     }
 //! }
 //!
@@ -109,5 +123,8 @@
 mod controller;
 mod widget;
 
-pub use controller::{EventContext, EventController};
+pub use controller::{
+    Broadcast, EventContext, EventController, ExclusiveGuard, LifecycleContext, LifecycleEvent,
+    Locatable, Propagation,
+};
 pub use widget::{EventfulWidget, InteractiveStatefulWidget, InteractiveWidget};
@@ -5,7 +5,10 @@ use ratatui::{
 };
 use std::{cell::RefCell, rc::Rc};
 
-use crate::{EventController, controller::EventContext};
+use crate::{
+    EventController,
+    controller::{EventContext, ExclusiveGuard, LifecycleContext, LifecycleEvent, Propagation},
+};
 
 /// A trait that should be implemented by widgets that can handle events.
 ///
@@ -16,13 +19,31 @@ pub trait EventfulWidget<S, E> {
     /// This key is used to register and unregister event listeners for the widget.
     fn unique_key() -> String;
 
+    /// Returns the dispatch priority this widget registers with.
+    ///
+    /// Higher values are notified first; ties are broken by registration
+    /// order. Defaults to `0`.
+    fn z_priority() -> i32 {
+        0
+    }
+
     /// Handles incoming events for the widget.
     ///
     /// # Arguments
     /// - `ctx`: The [`EventContext`] containing the [`EventController`] and the event `E`.
     /// - `state`: The mutable application state `S`.
     /// - `area`: The area of the widget's last render.
-    fn on_event(ctx: EventContext<S, E>, state: &mut S, area: Option<Rect>);
+    ///
+    /// Return [`Propagation::Consume`] to stop the event from reaching
+    /// lower-priority listeners, or [`Propagation::Ignore`] to let it
+    /// propagate.
+    fn on_event(ctx: EventContext<S, E>, state: &mut S, area: Option<Rect>) -> Propagation;
+
+    /// Handles a [`LifecycleEvent`] (init, resize, focus-changed), delivered
+    /// independently of `E`.
+    ///
+    /// Defaults to a no-op; override to opt in.
+    fn on_lifecycle(_ctx: LifecycleContext<S, E>, _state: &mut S, _event: LifecycleEvent) {}
 }
 
 /// A macro to create an `InteractiveWidget` that integrates with an event controller.
@@ -57,13 +78,16 @@ macro_rules! interactive_widget {
                 // Clone the controller and register the event handler callback.
                 let controller_clone = controller.rc_clone();
                 let key = &W::unique_key();
-                controller_clone.add_listener(key, {
+                controller_clone.add_listener(key, W::z_priority(), {
                     let area_clone = Rc::clone(&area);
                     move |ctx, state| {
                         let area = area_clone.borrow();
-                        W::on_event(ctx, state, *area);
+                        W::on_event(ctx, state, *area)
                     }
                 });
+                controller_clone.add_lifecycle_listener(key, |ctx, state, event| {
+                    W::on_lifecycle(ctx, state, event);
+                });
 
                 Self {
                     widget,
@@ -71,6 +95,22 @@ macro_rules! interactive_widget {
                     area,
                 }
             }
+
+            /// Creates a new `$name` and immediately grabs exclusive input
+            /// capture for it, the way a modal or popup would.
+            ///
+            /// Equivalent to calling [`Self::new`] followed by
+            /// [`EventController::push_exclusive`]. The returned guard
+            /// releases the capture when dropped.
+            #[must_use]
+            pub fn new_exclusive(
+                widget: W,
+                controller: &EventController<S, E>,
+            ) -> (Self, ExclusiveGuard<S, E>) {
+                let widget = Self::new(widget, controller);
+                let guard = controller.push_exclusive(&W::unique_key());
+                (widget, guard)
+            }
         }
 
         impl<S, E, W> Drop for $name<S, E, W>
@@ -96,6 +136,7 @@ where
 {
     fn render_ref(&self, area: Rect, buf: &mut Buffer) {
         *self.area.borrow_mut() = Some(area);
+        self.controller.update_area(&W::unique_key(), area);
         self.widget.render_ref(area, buf);
     }
 }
@@ -108,6 +149,7 @@ where
 
     fn render_ref(&self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
         *self.area.borrow_mut() = Some(area);
+        self.controller.update_area(&W::unique_key(), area);
         self.widget.render_ref(area, buf, state);
     }
 }